@@ -0,0 +1 @@
+pub mod route_recognizer;
@@ -0,0 +1,2 @@
+pub mod nfa;
+pub mod router;
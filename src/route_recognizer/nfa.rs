@@ -173,8 +173,8 @@ impl<T> State<T> {
 }
 
 pub struct Match<'a> {
-  state: uint,
-  captures: ~[&'a str]
+  pub state: uint,
+  pub captures: ~[&'a str]
 }
 
 impl<'a> Match<'a> {
@@ -276,6 +276,11 @@ impl<T> NFA<T> {
     &mut self.states[state]
   }
 
+  #[inline]
+  pub fn metadata_ref<'a>(&'a self, state: uint) -> &'a Option<T> {
+    &self.get(state).metadata
+  }
+
   pub fn put(&mut self, index: uint, chars: CharacterClass) -> uint {
     {
       let state = self.get(index);
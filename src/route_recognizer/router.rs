@@ -0,0 +1,126 @@
+use std::hashmap::HashMap;
+use route_recognizer::nfa::{NFA, CharacterClass};
+
+/// A successful `Router::recognize` lookup: the handler that was registered
+/// for the matched route, plus the dynamic segments keyed by name.
+pub struct Match<'a, T> {
+  pub handler: &'a T,
+  pub params: HashMap<~str, ~str>
+}
+
+impl<'a, T> Match<'a, T> {
+  pub fn new<'a>(handler: &'a T, params: HashMap<~str, ~str>) -> Match<'a, T> {
+    Match{ handler: handler, params: params }
+  }
+}
+
+pub struct Router<T> {
+  nfa: NFA<T>,
+  names: HashMap<uint, ~[~str]>
+}
+
+impl<T> Router<T> {
+  pub fn new() -> Router<T> {
+    Router{ nfa: NFA::new(), names: HashMap::new() }
+  }
+
+  pub fn add(&mut self, route: &str, dest: T) {
+    let mut state = 0;
+    let mut names = ~[];
+
+    for segment in route.split('/') {
+      if segment.len() == 0 { continue; }
+
+      state = self.nfa.put(state, CharacterClass::valid_char('/'));
+
+      if segment.starts_with(":") {
+        names.push(segment.slice_from(1).to_owned());
+        state = self.add_capturing_segment(state, CharacterClass::invalid_char('/'));
+      } else if segment.starts_with("*") {
+        names.push(segment.slice_from(1).to_owned());
+        state = self.add_capturing_segment(state, CharacterClass::any());
+      } else {
+        for char in segment.chars() {
+          state = self.nfa.put(state, CharacterClass::valid_char(char));
+        }
+      }
+    }
+
+    self.nfa.acceptance(state);
+    self.nfa.metadata(state, dest);
+    self.names.insert(state, names);
+  }
+
+  pub fn recognize<'a>(&'a self, path: &'a str) -> Result<Match<'a, T>, ~str> {
+    match self.nfa.process(path, |index| index) {
+      Ok(m) => {
+        let names = self.names.get(&m.state);
+        let mut params = HashMap::new();
+
+        for (name, capture) in names.iter().zip(m.captures.iter()) {
+          params.insert(name.to_owned(), capture.to_owned());
+        }
+
+        let handler = self.nfa.metadata_ref(m.state).get_ref();
+        Ok(Match::new(handler, params))
+      }
+      Err(s) => Err(s)
+    }
+  }
+
+  fn add_capturing_segment(&mut self, state: uint, chars: CharacterClass) -> uint {
+    let next = self.nfa.put(state, chars);
+    self.nfa.put_state(next, next);
+    self.nfa.start_capture(next);
+    self.nfa.end_capture(next);
+    next
+  }
+}
+
+#[test]
+fn basic_route() {
+  let mut router = Router::new();
+  router.add("/posts", "index");
+  router.add("/posts/:id", "show");
+
+  assert_eq!(*router.recognize("/posts").unwrap().handler, "index");
+  assert_eq!(*router.recognize("/posts/1").unwrap().handler, "show");
+}
+
+#[test]
+fn dynamic_segment_is_captured() {
+  let mut router = Router::new();
+  router.add("/posts/:id", "show");
+
+  let m = router.recognize("/posts/1").unwrap();
+  assert_eq!(*m.handler, "show");
+  assert_eq!(m.params.get(&~"id"), &~"1");
+}
+
+#[test]
+fn multiple_dynamic_segments_are_captured() {
+  let mut router = Router::new();
+  router.add("/posts/:post_id/comments/:id", "show");
+
+  let m = router.recognize("/posts/1/comments/2").unwrap();
+  assert_eq!(m.params.get(&~"post_id"), &~"1");
+  assert_eq!(m.params.get(&~"id"), &~"2");
+}
+
+#[test]
+fn glob_segment_is_captured() {
+  let mut router = Router::new();
+  router.add("/files/*path", "download");
+
+  let m = router.recognize("/files/a/b/c.txt").unwrap();
+  assert_eq!(*m.handler, "download");
+  assert_eq!(m.params.get(&~"path"), &~"a/b/c.txt");
+}
+
+#[test]
+fn unmatched_path_is_an_error() {
+  let mut router = Router::new();
+  router.add("/posts", "index");
+
+  assert!(router.recognize("/users").is_err());
+}